@@ -0,0 +1,151 @@
+// Copyright 2024, Linaro Limited
+// Author(s): Manos Pitsidianakis <manos.pitsidianakis@linaro.org>
+// SPDX-License-Identifier: GPL-2.0-or-later
+
+//! Procedural macros for the `qemu-api` crate.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    parse_macro_input, punctuated::Punctuated, Data, DeriveInput, Error, Expr, ExprLit, Field,
+    Fields, Lit, Meta, Token,
+};
+
+/// Return the first field of a `#[repr(C)]` struct, which QOM requires to be
+/// the parent instance (for instance structs) or the parent class struct (for
+/// class structs).
+fn first_field(input: &DeriveInput) -> Result<&Field, Error> {
+    let Data::Struct(s) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "#[derive(Object)] can only be used on structs",
+        ));
+    };
+    let Fields::Named(fields) = &s.fields else {
+        return Err(Error::new_spanned(
+            &s.fields,
+            "#[derive(Object)] requires a struct with named fields",
+        ));
+    };
+    fields.named.first().ok_or_else(|| {
+        Error::new_spanned(
+            &s.fields,
+            "#[derive(Object)] requires at least the parent field",
+        )
+    })
+}
+
+/// Check that the struct is declared `#[repr(C)]`, which the offset-0
+/// guarantee below relies on.
+fn check_repr_c(input: &DeriveInput) -> Result<(), Error> {
+    let is_repr_c = input.attrs.iter().any(|attr| {
+        if !attr.path().is_ident("repr") {
+            return false;
+        }
+        // `repr` can carry several comma-separated entries, e.g.
+        // `#[repr(C, align(8))]` or `#[repr(C, packed)]`; parse the list and
+        // look for a bare `C` among them.
+        attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+            .map(|entries| entries.iter().any(|m| m.path().is_ident("C")))
+            .unwrap_or(false)
+    });
+    if is_repr_c {
+        Ok(())
+    } else {
+        Err(Error::new_spanned(
+            &input.ident,
+            "#[derive(Object)] requires the struct to be #[repr(C)]",
+        ))
+    }
+}
+
+/// Extract the type name from the mandatory `#[type_name = "..."]` attribute.
+fn type_name(input: &DeriveInput) -> Result<String, Error> {
+    for attr in &input.attrs {
+        if !attr.path().is_ident("type_name") {
+            continue;
+        }
+        if let Meta::NameValue(nv) = &attr.meta {
+            if let Expr::Lit(ExprLit {
+                lit: Lit::Str(s), ..
+            }) = &nv.value
+            {
+                return Ok(s.value());
+            }
+        }
+        return Err(Error::new_spanned(
+            attr,
+            r#"#[type_name = "..."] expects a string literal"#,
+        ));
+    }
+    Err(Error::new_spanned(
+        &input.ident,
+        r#"#[derive(Object)] requires a #[type_name = "..."] attribute"#,
+    ))
+}
+
+fn derive_object_or_error(input: DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    check_repr_c(&input)?;
+    let name = &input.ident;
+    let parent = first_field(&input)?;
+    let parent_ident = parent.ident.as_ref().unwrap();
+    let parent_ty = &parent.ty;
+    let type_name = type_name(&input)?;
+
+    Ok(quote! {
+        const _: () = assert!(
+            ::core::mem::offset_of!(#name, #parent_ident) == 0,
+            concat!(
+                "the first field of ",
+                stringify!(#name),
+                " must be its QOM superclass",
+            ),
+        );
+
+        // A leaf declared with `#[derive(Object)]` reuses its parent's class
+        // struct; classes that add virtual methods implement `ObjectType` by
+        // hand instead.
+        unsafe impl ::qemu_api::qom::ObjectType for #name {
+            type Class = <#parent_ty as ::qemu_api::qom::ObjectType>::Class;
+            const TYPE_NAME: &'static ::core::ffi::CStr = unsafe {
+                ::core::ffi::CStr::from_bytes_with_nul_unchecked(
+                    ::core::concat!(#type_name, "\0").as_bytes(),
+                )
+            };
+        }
+
+        ::qemu_api::module_init! {
+            qom => {
+                ::qemu_api::qom::type_register_static::<#name>();
+            }
+        }
+    })
+}
+
+/// Derive the boilerplate needed to register a Rust-defined QOM type.
+///
+/// The annotated struct must be `#[repr(C)]`, carry a `#[type_name = "..."]`
+/// attribute, and have the parent instance as its first field; it must also
+/// implement [`ObjectImpl`](qemu_api::qom::ObjectImpl) to provide its
+/// `ParentType` and virtual methods.  The macro then:
+///
+/// * statically asserts that the parent field sits at offset 0, upholding the
+///   first-field-is-superclass invariant documented on
+///   [`ObjectType`](qemu_api::qom::ObjectType) (sound only for `#[repr(C)]`);
+///
+/// * implements [`ObjectType`](qemu_api::qom::ObjectType), reusing the
+///   parent's class struct and taking the name from `#[type_name]`;
+///
+/// * emits a [`module_init!`](qemu_api::module_init) entry point that calls
+///   [`type_register_static`](qemu_api::qom::type_register_static) so the type
+///   is registered with QOM automatically at start-up.
+///
+/// Subclass relationships for [`IsA`](qemu_api::qom::IsA) casting are declared
+/// separately with [`qom_isa!`](qemu_api::qom_isa).
+#[proc_macro_derive(Object, attributes(type_name))]
+pub fn derive_object(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_object_or_error(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
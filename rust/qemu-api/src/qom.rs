@@ -31,11 +31,57 @@
 //!   the source for this is the `*Impl` trait; the associated consts and
 //!   functions if needed are wrapped to map C types into Rust types.
 
-use std::{ffi::CStr, os::raw::c_void};
+use std::{
+    ffi::CStr,
+    os::raw::{c_char, c_void},
+};
 
 pub use bindings::{Object, ObjectClass};
 
-use crate::bindings::{self, TypeInfo};
+use crate::bindings::{self, InterfaceInfo, TypeInfo};
+
+/// Register a function to be run when the `qemu-api` module is loaded.
+///
+/// QEMU collects module initializers in a linker set and runs them at
+/// start-up, before any command-line option is parsed.  This macro is the
+/// Rust counterpart of the C `type_init()` / `module_init()` family: the body
+/// is wrapped in an `extern "C"` function and placed in the `.init_array`
+/// section so the C runtime calls it automatically.
+///
+/// It is mainly used by [`#[derive(Object)]`](crate::Object) to enqueue a
+/// type's registration, but can also be used directly:
+///
+/// ```ignore
+/// module_init! {
+///     qom => {
+///         type_register_static::<MyDevice>();
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! module_init {
+    ($type:ident => $body:block) => {
+        const _: () = {
+            #[used]
+            #[cfg_attr(
+                not(any(target_vendor = "apple", target_os = "windows")),
+                link_section = ".init_array"
+            )]
+            #[cfg_attr(target_vendor = "apple", link_section = "__DATA,__mod_init_func")]
+            #[cfg_attr(target_os = "windows", link_section = ".CRT$XCU")]
+            static LOAD_MODULE: extern "C" fn() = {
+                extern "C" fn __load() {
+                    // Silence the unused-variant warning for the module kind;
+                    // it documents intent at the call site.
+                    let _ = stringify!($type);
+                    $body
+                }
+
+                __load
+            };
+        };
+    };
+}
 
 unsafe extern "C" fn rust_instance_init<T: ObjectImpl>(obj: *mut Object) {
     // SAFETY: obj is an instance of T, since rust_instance_init<T>
@@ -55,14 +101,43 @@ unsafe extern "C" fn rust_instance_post_init<T: ObjectImpl>(obj: *mut Object) {
     T::INSTANCE_POST_INIT.unwrap()(unsafe { &mut *obj.cast::<T>() })
 }
 
-unsafe extern "C" fn rust_class_init<T: ObjectType + ClassInitImpl<T::Class>>(
+unsafe extern "C" fn rust_class_init<T: ObjectImpl>(
     klass: *mut ObjectClass,
     _data: *mut c_void,
 ) {
     // SAFETY: klass is a T::Class, since rust_class_init<T>
     // is called from QOM core as the class_init function
     // for class T
-    T::class_init(unsafe { &mut *klass.cast::<T::Class>() })
+    T::class_init(unsafe { &mut *klass.cast::<T::Class>() });
+
+    // Now that the class and its whole superclass chain are initialized, fill
+    // in the vtable of each implemented interface.  QOM has already created
+    // the interface class structs (because their names are listed in
+    // `TypeInfo::interfaces`), so look each one up inside `klass` and run its
+    // trampoline against it.
+    for iface in <T as ObjectImpl>::INTERFACES {
+        // SAFETY: klass is a valid class being initialized; the cast returns
+        // either null or a pointer to the interface class struct within klass.
+        let iface_class = unsafe { bindings::object_class_dynamic_cast(klass, iface.name.as_ptr()) };
+        if !iface_class.is_null() {
+            // SAFETY: iface_class is the interface class struct that
+            // `iface.init` expects (see `Interface::new`).
+            unsafe { (iface.init)(iface_class, core::ptr::null_mut()) };
+        }
+    }
+}
+
+unsafe extern "C" fn rust_interface_init<T, Interface>(klass: *mut ObjectClass, _data: *mut c_void)
+where
+    Interface: ObjectInterface,
+    T: ObjectType + ClassInitImpl<Interface::Class>,
+{
+    // SAFETY: klass is the interface's own class struct, since
+    // rust_interface_init::<T, Interface> is invoked by `rust_class_init`
+    // with the result of `object_class_dynamic_cast` to the interface.  Every
+    // interface class struct starts with an `ObjectClass`, so the cast to
+    // Interface::Class is sound.
+    T::class_init(unsafe { &mut *klass.cast::<Interface::Class>() })
 }
 
 /// Trait exposed by all structs corresponding to QOM objects.
@@ -96,6 +171,161 @@ pub unsafe trait ObjectType: Sized {
     /// The name of the type, which can be passed to `object_new()` to
     /// generate an instance of this type.
     const TYPE_NAME: &'static CStr;
+
+    /// Reinterpret a reference to `Self` as a reference to one of its
+    /// superclasses `U`.  This is a zero-cost operation: it relies on the
+    /// `ObjectType` invariant that the first field of the instance struct is
+    /// the superclass, so the cast is guaranteed correct at compile time by
+    /// the [`IsA`] bound.
+    fn upcast<U: ObjectType>(&self) -> &U
+    where
+        Self: IsA<U>,
+    {
+        // SAFETY: `Self: IsA<U>` guarantees that `U` is a superclass of `Self`
+        // and, by the first-field invariant on `ObjectType`, that a `*Self`
+        // and the contained `*U` share the same address.
+        unsafe { &*(self as *const Self).cast::<U>() }
+    }
+
+    /// Attempt to cast a reference to `Self` into a reference to `U`, which
+    /// need not be related to `Self` at compile time.  The cast is checked at
+    /// runtime through `object_dynamic_cast` and returns `None` if the object
+    /// is not an instance of `U`.
+    fn dynamic_cast<U: ObjectType>(&self) -> Option<&U> {
+        // SAFETY: `object_dynamic_cast` either returns null or a pointer that
+        // really points to an instance of `U`; the borrow is tied to `self`.
+        unsafe {
+            let result = bindings::object_dynamic_cast(
+                (self as *const Self).cast_mut().cast::<Object>(),
+                U::TYPE_NAME.as_ptr(),
+            );
+            result.cast::<U>().as_ref()
+        }
+    }
+}
+
+/// Marker trait asserting that `Self` is `U` or one of its subclasses, so that
+/// a `&Self` can be reinterpreted as a `&U` with [`ObjectType::upcast`].
+///
+/// It is implemented reflexively for every [`ObjectType`], and once per
+/// ancestor through the [`qom_isa!`](crate::qom_isa) macro, which a type lists
+/// alongside its [`#[derive(Object)]`](crate::Object) declaration to emit one
+/// `IsA<Ancestor>` impl per superclass up to [`Object`].
+///
+/// A recursive blanket impl over [`ObjectImpl::ParentType`] cannot be used:
+/// it overlaps the reflexive impl for the case `U == Self` (coherence cannot
+/// prove `Self::ParentType: IsA<Self>` is unsatisfiable), so `rustc` rejects
+/// it with E0119.  The superclass chain is therefore unrolled into explicit
+/// impls by [`qom_isa!`](crate::qom_isa) instead.
+///
+/// # Safety
+///
+/// Implementors must guarantee that a `*const Self` can be reinterpreted as a
+/// `*const U` — the first-field invariant documented on [`ObjectType`].  Do
+/// not implement this trait manually; use [`qom_isa!`](crate::qom_isa).
+pub unsafe trait IsA<U: ObjectType>: ObjectType {}
+
+// SAFETY: reflexivity — a reference can always be used as itself.
+unsafe impl<T: ObjectType> IsA<T> for T {}
+
+/// Assert that `$struct` is a subclass of each listed ancestor by emitting the
+/// corresponding [`IsA`] implementations.
+///
+/// ```ignore
+/// // MyDevice { parent: DeviceState, ... }, DeviceState { parent: Object }
+/// qom_isa!(MyDevice: DeviceState, Object);
+/// ```
+///
+/// # Safety
+///
+/// Each ancestor must genuinely be reachable by following the first field of
+/// `$struct` (the invariant on [`ObjectType`]); listing an unrelated type is
+/// unsound.  A type declared with [`#[derive(Object)]`](crate::Object) gets
+/// only the reflexive `IsA<Self>`, so its author must invoke this macro by
+/// hand to make [`upcast`](ObjectType::upcast) to a superclass available.
+#[macro_export]
+macro_rules! qom_isa {
+    ($struct:ty : $($ancestor:ty),+ $(,)?) => {
+        $(
+            // SAFETY: the caller asserts $ancestor is an ancestor of $struct,
+            // so a `*const $struct` can be reinterpreted as a `*const $ancestor`.
+            unsafe impl $crate::qom::IsA<$ancestor> for $struct {}
+        )+
+    };
+}
+
+/// Trait exposed by all structs corresponding to QOM interfaces.
+///
+/// Unlike [`ObjectType`], an interface is never instantiated on its own; it
+/// only contributes a class struct (its vtable) to the types that implement
+/// it.  A Rust type lists the interfaces it provides through
+/// [`ObjectImpl::INTERFACES`] and fills in each interface vtable via its
+/// [`ClassInitImpl`] implementation for [`Class`](ObjectInterface::Class).
+///
+/// # Safety
+///
+/// - `Class` must match the class struct registered for the interface under
+///   `TYPE_NAME`;
+///
+/// - as for any other QOM class struct, the first field of `Class` must be an
+///   [`ObjectClass`].
+pub unsafe trait ObjectInterface: Sized {
+    /// The class struct that holds the interface's virtual methods.
+    type Class;
+
+    /// The name of the interface, as registered with QOM.
+    const TYPE_NAME: &'static CStr;
+}
+
+/// Trait that ties an interface to a type `T` that implements it.
+///
+/// It is implemented automatically for every `(I, T)` pair such that `T`
+/// provides a [`ClassInitImpl`] for the interface's class struct, and exposes
+/// the `interface_init` trampoline that [`Interface::new`] records in
+/// [`ObjectImpl::INTERFACES`].
+///
+/// # Safety
+///
+/// [`INTERFACE_INIT`](IsImplementable::INTERFACE_INIT) is run by
+/// [`rust_class_init`] with the interface class struct of `T`, while that
+/// class is being built.
+pub unsafe trait IsImplementable<T: ObjectType>: ObjectInterface {
+    /// Trampoline that forwards to `T`'s [`ClassInitImpl`] for the interface.
+    const INTERFACE_INIT: unsafe extern "C" fn(klass: *mut ObjectClass, data: *mut c_void);
+}
+
+unsafe impl<T, I> IsImplementable<T> for I
+where
+    I: ObjectInterface,
+    T: ObjectType + ClassInitImpl<I::Class>,
+{
+    const INTERFACE_INIT: unsafe extern "C" fn(klass: *mut ObjectClass, data: *mut c_void) =
+        rust_interface_init::<T, I>;
+}
+
+/// One interface implemented by a Rust-defined type.
+///
+/// It pairs the interface's registered name — used to populate
+/// [`TypeInfo::interfaces`] so QOM creates the interface class struct — with
+/// the trampoline that fills that struct with `T`'s methods.  Build entries
+/// with [`Interface::new`] and list them in [`ObjectImpl::INTERFACES`].
+pub struct Interface {
+    name: &'static CStr,
+    init: unsafe extern "C" fn(klass: *mut ObjectClass, data: *mut c_void),
+}
+
+impl Interface {
+    /// Declare that `T` implements the interface `I`.
+    pub const fn new<I, T>() -> Self
+    where
+        I: IsImplementable<T>,
+        T: ObjectType,
+    {
+        Interface {
+            name: I::TYPE_NAME,
+            init: <I as IsImplementable<T>>::INTERFACE_INIT,
+        }
+    }
 }
 
 /// Trait a type must implement to be registered with QEMU.
@@ -129,6 +359,26 @@ pub trait ObjectImpl: ObjectType + ClassInitImpl<Self::Class> {
         unsafe extern "C" fn(klass: *mut ObjectClass, data: *mut c_void),
     > = None;
 
+    /// The QOM interfaces implemented by the type.  Each entry is built with
+    /// [`Interface::new`], for example:
+    ///
+    /// ```ignore
+    /// const INTERFACES: &'static [Interface] = &[
+    ///     Interface::new::<ResettableClass, Self>(),
+    /// ];
+    /// ```
+    ///
+    /// [`type_register_static`] turns the entries' names into the
+    /// NULL-terminated [`TypeInfo::interfaces`] array QOM expects, and
+    /// [`rust_class_init`] runs each entry's trampoline to fill in the
+    /// interface vtable.
+    const INTERFACES: &'static [Interface] = &[];
+
+    /// The QOM properties exposed by the type.  Defaults to none; each entry
+    /// is registered on the class during `class_init`.  See [`Property`] for
+    /// the available constructors.
+    const PROPERTIES: &'static [Property] = &[];
+
     const TYPE_INFO: TypeInfo = TypeInfo {
         name: Self::TYPE_NAME.as_ptr(),
         parent: Self::ParentType::TYPE_NAME.as_ptr(),
@@ -148,6 +398,8 @@ pub trait ObjectImpl: ObjectType + ClassInitImpl<Self::Class> {
         class_init: Some(rust_class_init::<Self>),
         class_base_init: Self::CLASS_BASE_INIT,
         class_data: core::ptr::null_mut(),
+        // Populated at registration time by `type_register_static`, which
+        // turns `INTERFACES` into the NULL-terminated array QOM requires.
         interfaces: core::ptr::null_mut(),
     };
 
@@ -193,7 +445,9 @@ pub trait ObjectImpl: ObjectType + ClassInitImpl<Self::Class> {
 ///   own class struct `FooClass` and implement `ClassInitImpl<FooClass>`.
 ///   `ClassInitImpl<FooClass>`'s `class_init` method will then forward to
 ///   multiple other `class_init`s, for the interfaces as well as the
-///   superclass. (Note that there is no Rust example yet for using interfaces).
+///   superclass.  The interface vtables are filled in through the
+///   [`IsImplementable`] trampolines listed in [`ObjectImpl::INTERFACES`];
+///   see [`ObjectInterface`] for the interface side of this machinery.
 ///
 /// * for classes implemented outside the ``qemu-api`` crate, it's not possible
 ///   to add blanket implementations like the above one, due to orphan rules. In
@@ -255,6 +509,371 @@ where
         if <T as ObjectImpl>::UNPARENT.is_some() {
             oc.unparent = Some(rust_unparent_fn::<T>);
         }
+        for prop in <T as ObjectImpl>::PROPERTIES {
+            prop.register(oc);
+        }
+    }
+}
+
+/// Signature of the `get`/`set` trampolines stored in a [`Property`]; it
+/// matches QOM's `ObjectPropertyAccessor`.
+pub type PropertyAccessor = unsafe extern "C" fn(
+    obj: *mut Object,
+    v: *mut bindings::Visitor,
+    name: *const c_char,
+    opaque: *mut c_void,
+    errp: *mut *mut bindings::Error,
+);
+
+/// How a [`Property`] is wired into QOM.
+enum PropertyKind {
+    /// A property backed by getter/setter trampolines, as registered with
+    /// `object_class_property_add`.  `opaque` is forwarded to both and, for
+    /// the field-backed constructors, carries the byte offset of the field
+    /// inside the instance struct.
+    Accessor {
+        type_: &'static CStr,
+        get: Option<PropertyAccessor>,
+        set: Option<PropertyAccessor>,
+        opaque: usize,
+    },
+    /// An unsigned-integer property backed by a field of the instance struct.
+    /// The accessors receive `&range` as their `opaque`, carrying the field
+    /// offset and the `[min, max]` bounds enforced on writes.
+    Uint {
+        type_: &'static CStr,
+        get: PropertyAccessor,
+        set: PropertyAccessor,
+        range: UintRange,
+    },
+    /// A link to another QOM object of type `target_type`, stored at `offset`
+    /// inside the instance struct; registered with
+    /// `object_class_property_add_link`.
+    Link {
+        target_type: &'static CStr,
+        offset: usize,
+    },
+}
+
+/// Offset and inclusive bounds of an integer property, passed to the accessor
+/// trampolines through their `opaque` pointer.
+pub struct UintRange {
+    offset: usize,
+    min: u64,
+    max: u64,
+}
+
+/// Unsigned-integer types that can back a QOM property.
+///
+/// # Safety
+///
+/// [`TYPE_NAME`](PropertyUint::TYPE_NAME) must name the QOM visitor type that
+/// [`visit`](PropertyUint::visit) uses, so that exactly `size_of::<Self>()`
+/// bytes of the backing field are read and written.
+pub unsafe trait PropertyUint: Copy + 'static {
+    /// The QOM type name (e.g. `"uint8"`), matching the width of `Self`.
+    const TYPE_NAME: &'static CStr;
+
+    /// Widen to `u64` for range checking.
+    fn widen(self) -> u64;
+
+    /// Narrow a previously range-checked `u64` back to `Self`.
+    fn narrow(val: u64) -> Self;
+
+    /// Forward to the width-specific QOM visitor (`visit_type_uintN`).
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be the valid pointers passed to an
+    /// `ObjectPropertyAccessor`.
+    unsafe fn visit(
+        v: *mut bindings::Visitor,
+        name: *const c_char,
+        val: *mut Self,
+        errp: *mut *mut bindings::Error,
+    ) -> bool;
+}
+
+macro_rules! impl_property_uint {
+    ($ty:ty, $name:literal, $visit:ident) => {
+        // SAFETY: TYPE_NAME matches the visitor, which reads/writes a $ty.
+        unsafe impl PropertyUint for $ty {
+            const TYPE_NAME: &'static CStr = $name;
+
+            fn widen(self) -> u64 {
+                self as u64
+            }
+
+            fn narrow(val: u64) -> Self {
+                val as Self
+            }
+
+            unsafe fn visit(
+                v: *mut bindings::Visitor,
+                name: *const c_char,
+                val: *mut Self,
+                errp: *mut *mut bindings::Error,
+            ) -> bool {
+                // SAFETY: forwarded from the accessor trampoline.
+                unsafe { bindings::$visit(v, name, val, errp) }
+            }
+        }
+    };
+}
+
+impl_property_uint!(u8, c"uint8", visit_type_uint8);
+impl_property_uint!(u16, c"uint16", visit_type_uint16);
+impl_property_uint!(u32, c"uint32", visit_type_uint32);
+impl_property_uint!(u64, c"uint64", visit_type_uint64);
+
+/// A QOM property declared by a Rust-defined object.
+///
+/// Properties are listed in [`ObjectImpl::PROPERTIES`] and registered on the
+/// class during `class_init`.  The convenience constructors cover the common
+/// cases — a field-backed [`bool`](Property::new_bool) or
+/// [integer](Property::new_uint), a [link](Property::new_link) to another
+/// object — and generate the `extern "C"` accessors that locate the backing
+/// field at its byte offset from the incoming `*mut Object`;
+/// [`new`](Property::new) takes custom accessors for everything else.
+pub struct Property {
+    name: &'static CStr,
+    kind: PropertyKind,
+}
+
+impl Property {
+    /// Declare a property with custom getter/setter trampolines, mirroring a
+    /// bare `object_class_property_add`.  `opaque` is passed unchanged to both
+    /// accessors.
+    pub const fn new(
+        name: &'static CStr,
+        type_: &'static CStr,
+        get: Option<PropertyAccessor>,
+        set: Option<PropertyAccessor>,
+        opaque: usize,
+    ) -> Self {
+        Property {
+            name,
+            kind: PropertyKind::Accessor {
+                type_,
+                get,
+                set,
+                opaque,
+            },
+        }
+    }
+
+    /// Declare a read/write `bool` property backed by a field of `T` at
+    /// `offset` (typically `core::mem::offset_of!(T, field)`).
+    pub const fn new_bool<T: ObjectImpl>(name: &'static CStr, offset: usize) -> Self {
+        Property {
+            name,
+            kind: PropertyKind::Accessor {
+                type_: c"bool",
+                get: Some(rust_get_bool::<T>),
+                set: Some(rust_set_bool::<T>),
+                opaque: offset,
+            },
+        }
+    }
+
+    /// Declare a read/write unsigned-integer property backed by a field of
+    /// type `V` of `T` at `offset`.  `V` determines the QOM type and the
+    /// number of bytes touched, so the accessors never read or write past the
+    /// backing field.  Writes outside the inclusive `[min, max]` range are
+    /// rejected with an error.
+    pub const fn new_uint<T: ObjectImpl, V: PropertyUint>(
+        name: &'static CStr,
+        offset: usize,
+        min: u64,
+        max: u64,
+    ) -> Self {
+        Property {
+            name,
+            kind: PropertyKind::Uint {
+                type_: V::TYPE_NAME,
+                get: rust_get_uint::<T, V>,
+                set: rust_set_uint::<T, V>,
+                range: UintRange { offset, min, max },
+            },
+        }
+    }
+
+    /// Declare a link property to another QOM object of type `target_type`,
+    /// stored in a `*mut Object` field of `T` at `offset`.  The link is
+    /// registered as a non-strong reference with no custom set-time check;
+    /// callers needing a strong link or a check must register it by hand.
+    pub const fn new_link<T: ObjectImpl>(
+        name: &'static CStr,
+        target_type: &'static CStr,
+        offset: usize,
+    ) -> Self {
+        let _ = core::marker::PhantomData::<T>;
+        Property {
+            name,
+            kind: PropertyKind::Link {
+                target_type,
+                offset,
+            },
+        }
+    }
+
+    fn register(&self, oc: &mut ObjectClass) {
+        let oc: *mut ObjectClass = oc;
+        match self.kind {
+            PropertyKind::Accessor {
+                type_,
+                get,
+                set,
+                opaque,
+            } => {
+                // SAFETY: `oc` is a valid class being initialized and all the
+                // pointers passed below live for as long as the class.
+                unsafe {
+                    bindings::object_class_property_add(
+                        oc,
+                        self.name.as_ptr(),
+                        type_.as_ptr(),
+                        get,
+                        set,
+                        None,
+                        opaque as *mut c_void,
+                    );
+                }
+            }
+            PropertyKind::Uint {
+                type_,
+                get,
+                set,
+                ref range,
+            } => {
+                // SAFETY: `range` lives in the 'static `PROPERTIES` array, so
+                // the `opaque` pointer stays valid for the life of the class.
+                unsafe {
+                    bindings::object_class_property_add(
+                        oc,
+                        self.name.as_ptr(),
+                        type_.as_ptr(),
+                        Some(get),
+                        Some(set),
+                        None,
+                        (range as *const UintRange).cast_mut().cast::<c_void>(),
+                    );
+                }
+            }
+            PropertyKind::Link {
+                target_type,
+                offset,
+            } => {
+                // SAFETY: as above; `offset` points at a `*mut Object` field.
+                // The C `offset` parameter is a `ptrdiff_t`, i.e. `isize`.
+                //
+                // A `None` check with empty flags registers a non-strong link
+                // with no set-time type check beyond `target_type`; that is
+                // the conventional default for a plain link property (callers
+                // that need `OBJ_PROP_LINK_STRONG` or a custom check should
+                // register the link by hand).
+                unsafe {
+                    bindings::object_class_property_add_link(
+                        oc,
+                        self.name.as_ptr(),
+                        target_type.as_ptr(),
+                        offset as isize,
+                        None,
+                        0,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Return a reference to the field of `T` at byte `offset`.
+///
+/// # Safety
+///
+/// `obj` must point to a live instance of `T` and `offset` must be the offset
+/// of a field of type `V` inside `T`.
+unsafe fn field_ref<T, V>(obj: *mut Object, offset: usize) -> *mut V {
+    // The `Object` is the first field of `T` (directly or transitively), so
+    // `obj` and the instance share an address.
+    unsafe { (obj.cast::<u8>()).add(offset).cast::<V>() }
+}
+
+unsafe extern "C" fn rust_get_bool<T: ObjectImpl>(
+    obj: *mut Object,
+    v: *mut bindings::Visitor,
+    name: *const c_char,
+    opaque: *mut c_void,
+    errp: *mut *mut bindings::Error,
+) {
+    // SAFETY: opaque is the offset of a `bool` field in T, and obj is a T.
+    unsafe {
+        let mut val = *field_ref::<T, bool>(obj, opaque as usize);
+        bindings::visit_type_bool(v, name, &mut val, errp);
+    }
+}
+
+unsafe extern "C" fn rust_set_bool<T: ObjectImpl>(
+    obj: *mut Object,
+    v: *mut bindings::Visitor,
+    name: *const c_char,
+    opaque: *mut c_void,
+    errp: *mut *mut bindings::Error,
+) {
+    // SAFETY: as for rust_get_bool.
+    unsafe {
+        let mut val = false;
+        if bindings::visit_type_bool(v, name, &mut val, errp) {
+            *field_ref::<T, bool>(obj, opaque as usize) = val;
+        }
+    }
+}
+
+unsafe extern "C" fn rust_get_uint<T: ObjectImpl, V: PropertyUint>(
+    obj: *mut Object,
+    v: *mut bindings::Visitor,
+    name: *const c_char,
+    opaque: *mut c_void,
+    errp: *mut *mut bindings::Error,
+) {
+    // SAFETY: opaque points to the property's UintRange and obj is a T; the
+    // field read is exactly `size_of::<V>()` bytes wide.
+    unsafe {
+        let range = &*opaque.cast::<UintRange>();
+        let mut val = *field_ref::<T, V>(obj, range.offset);
+        V::visit(v, name, &mut val, errp);
+    }
+}
+
+unsafe extern "C" fn rust_set_uint<T: ObjectImpl, V: PropertyUint>(
+    obj: *mut Object,
+    v: *mut bindings::Visitor,
+    name: *const c_char,
+    opaque: *mut c_void,
+    errp: *mut *mut bindings::Error,
+) {
+    // SAFETY: as for rust_get_uint.
+    unsafe {
+        let range = &*opaque.cast::<UintRange>();
+        let mut val = V::narrow(0);
+        if !V::visit(v, name, &mut val, errp) {
+            return;
+        }
+        let widened = val.widen();
+        if widened < range.min || widened > range.max {
+            bindings::error_setg_internal(
+                errp,
+                c"qom.rs".as_ptr(),
+                0,
+                c"rust_set_uint".as_ptr(),
+                c"value %llu is out of range [%llu, %llu]".as_ptr(),
+                widened,
+                range.min,
+                range.max,
+            );
+            return;
+        }
+        *field_ref::<T, V>(obj, range.offset) = val;
     }
 }
 
@@ -263,3 +882,107 @@ unsafe impl ObjectType for Object {
     const TYPE_NAME: &'static CStr =
         unsafe { CStr::from_bytes_with_nul_unchecked(bindings::TYPE_OBJECT) };
 }
+
+/// Description of a leaf class that adds no instance state or virtual methods.
+///
+/// Following glib's `subclass::basic` module, an author who only needs to give
+/// a type a name and a parent implements this small trait on a marker type
+/// instead of hand-writing a `#[repr(C)]` instance struct, a class struct and
+/// their `ObjectType`/`ObjectImpl`/`ClassInitImpl` impls.  The generic
+/// [`InstanceState<T>`]/[`ClassState<T>`] then carry the actual layout, and
+/// the blanket impls below make `InstanceState<T>` the registrable type.
+pub trait LeafObjectImpl {
+    /// The parent of the type; see [`ObjectImpl::ParentType`].
+    type ParentType: ObjectType;
+
+    /// The name of the type; see [`ObjectType::TYPE_NAME`].
+    const TYPE_NAME: &'static CStr;
+
+    /// Whether the type can be instantiated; see [`ObjectImpl::ABSTRACT`].
+    const ABSTRACT: bool = false;
+}
+
+/// Generic instance struct for a [`LeafObjectImpl`] marker `T`.
+///
+/// It holds only the parent instance (the `PhantomData` is zero-sized), so the
+/// first-field-is-superclass invariant of [`ObjectType`] holds by
+/// construction.  `InstanceState<T>`, not `T`, is the type registered with
+/// QOM, and its `instance_size` is that of the parent — exactly right for a
+/// leaf that adds no state.
+#[repr(C)]
+pub struct InstanceState<T: LeafObjectImpl> {
+    /// The parent instance.
+    pub parent: T::ParentType,
+    _marker: core::marker::PhantomData<T>,
+}
+
+/// Generic class struct matching [`InstanceState`], holding nothing but the
+/// parent class struct.
+#[repr(C)]
+pub struct ClassState<T: LeafObjectImpl> {
+    /// The parent class struct.
+    pub parent_class: <T::ParentType as ObjectType>::Class,
+    _marker: core::marker::PhantomData<T>,
+}
+
+// SAFETY: `InstanceState<T>` is `#[repr(C)]` and its only non-zero-sized field
+// is the parent instance, so it satisfies the invariants of `ObjectType`; the
+// class struct is the matching `ClassState<T>`.
+unsafe impl<T: LeafObjectImpl> ObjectType for InstanceState<T> {
+    type Class = ClassState<T>;
+    const TYPE_NAME: &'static CStr = T::TYPE_NAME;
+}
+
+impl<T: LeafObjectImpl> ObjectImpl for InstanceState<T> {
+    type ParentType = T::ParentType;
+    const ABSTRACT: bool = T::ABSTRACT;
+}
+
+impl<T: LeafObjectImpl> ClassInitImpl<ClassState<T>> for InstanceState<T> {
+    fn class_init(klass: &mut ClassState<T>) {
+        // A leaf adds no virtual methods; the parent class struct is already
+        // initialized by QOM, so only the `ObjectClass`-level wiring
+        // (properties, unparent) from `ObjectImpl` needs to run.  The
+        // `ObjectClass` is the shared prefix of `ClassState<T>`.
+        // SAFETY: by the first-field invariant the `ObjectClass` sits at the
+        // start of `parent_class`, hence at the start of `ClassState<T>`.
+        let oc = unsafe { &mut *(klass as *mut ClassState<T>).cast::<ObjectClass>() };
+        <Self as ClassInitImpl<ObjectClass>>::class_init(oc);
+    }
+}
+
+/// Register a type defined in Rust with QOM.
+///
+/// This is a thin wrapper around `type_register_static()` that derives the
+/// [`TypeInfo`] from [`ObjectImpl::TYPE_INFO`].  It is normally not called
+/// directly: the [`#[derive(Object)]`](crate::Object) macro emits a
+/// [`module_init!`](crate::module_init) entry point that calls it for every
+/// annotated type when QEMU loads the module.
+pub fn type_register_static<T: ObjectImpl>() {
+    // `type_register_static` copies the `TypeInfo` fields into an internal
+    // `TypeImpl` and `g_strdup`s the interface type names, so a stack
+    // descriptor and a local array are enough — nothing needs to outlive the
+    // call.
+    let mut info = T::TYPE_INFO;
+
+    // Build the NULL-terminated `InterfaceInfo` array QOM walks.  Keep it
+    // alive until after registration.
+    let mut interfaces: Vec<InterfaceInfo> = <T as ObjectImpl>::INTERFACES
+        .iter()
+        .map(|iface| InterfaceInfo {
+            type_: iface.name.as_ptr(),
+        })
+        .collect();
+    if !interfaces.is_empty() {
+        interfaces.push(InterfaceInfo {
+            type_: core::ptr::null(),
+        });
+        info.interfaces = interfaces.as_mut_ptr();
+    }
+
+    // SAFETY: `info` (and the array it points at) are valid for the duration
+    // of the call, and the descriptor accurately describes `T`.
+    unsafe {
+        bindings::type_register_static(&info);
+    }
+}